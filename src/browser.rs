@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process::Command;
 
 use rusqlite::{Connection, Result as SqliteResult};
@@ -16,13 +16,78 @@ pub enum Browser {
     Unknown,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-pub struct Bookmark {
+/// A leaf bookmark, enriched with the metadata browsers keep alongside it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BookmarkNode {
     pub name: String,
     pub url: String,
+    pub tags: Vec<String>,
+    /// Seconds since the Unix epoch, when the browser recorded one.
+    pub date_added: Option<i64>,
+}
+
+/// A folder in the bookmark hierarchy, holding nested folders and bookmarks.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FolderNode {
+    pub title: String,
+    pub children: Vec<BookmarkTree>,
+}
+
+/// A node in the preserved bookmark hierarchy, modeled on Mozilla's places
+/// bookmark tree so both Chromium and Firefox imports share one shape.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum BookmarkTree {
+    Folder(FolderNode),
+    Bookmark(BookmarkNode),
+}
+
+impl BookmarkTree {
+    pub fn count_bookmarks(&self) -> usize {
+        match self {
+            BookmarkTree::Bookmark(_) => 1,
+            BookmarkTree::Folder(folder) => {
+                folder.children.iter().map(Self::count_bookmarks).sum()
+            }
+        }
+    }
+
+    /// Flattens the tree into a plain list of bookmarks, for callers that
+    /// don't need the folder hierarchy (e.g. the local bookmark store).
+    pub fn flatten(&self, out: &mut Vec<BookmarkNode>) {
+        match self {
+            BookmarkTree::Bookmark(node) => out.push(node.clone()),
+            BookmarkTree::Folder(folder) => {
+                for child in &folder.children {
+                    child.flatten(out);
+                }
+            }
+        }
+    }
+}
+
+pub fn count_bookmarks(trees: &[BookmarkTree]) -> usize {
+    trees.iter().map(BookmarkTree::count_bookmarks).sum()
 }
 
 impl Browser {
+    fn from_default_browser_str(browser_str: &str) -> Self {
+        if browser_str.contains("zen") {
+            Browser::Zen
+        } else if browser_str.contains("chrome") {
+            Browser::Chrome
+        } else if browser_str.contains("firefox") {
+            Browser::Firefox
+        } else if browser_str.contains("brave") {
+            Browser::Brave
+        } else if browser_str.contains("edge") {
+            Browser::Edge
+        } else {
+            Browser::Unknown
+        }
+    }
+
+    #[cfg(target_os = "linux")]
     pub fn detect() -> Self {
         // Try xdg-settings first
         if let Ok(output) = Command::new("xdg-settings")
@@ -30,111 +95,252 @@ impl Browser {
             .output()
         {
             let browser_str = String::from_utf8_lossy(&output.stdout).to_lowercase();
+            let detected = Self::from_default_browser_str(&browser_str);
+            if !matches!(detected, Browser::Unknown) {
+                return detected;
+            }
+        }
+
+        Browser::Unknown
+    }
 
-            if browser_str.contains("zen") {
-                return Browser::Zen;
-            } else if browser_str.contains("chrome") {
-                return Browser::Chrome;
-            } else if browser_str.contains("firefox") {
-                return Browser::Firefox;
-            } else if browser_str.contains("brave") {
-                return Browser::Brave;
-            } else if browser_str.contains("edge") {
-                return Browser::Edge;
+    #[cfg(target_os = "macos")]
+    pub fn detect() -> Self {
+        // xdg-settings doesn't exist on macOS; ask LaunchServices which app
+        // handles the "https" URL scheme instead.
+        if let Some(bundle_id) = Self::macos_https_handler() {
+            let detected = Self::from_default_browser_str(&bundle_id);
+            if !matches!(detected, Browser::Unknown) {
+                return detected;
             }
         }
 
         Browser::Unknown
     }
 
-    pub fn bookmark_path(&self) -> Option<PathBuf> {
+    /// The bundle id LaunchServices currently hands "https" URLs to.
+    ///
+    /// `defaults read .../LSHandlerRoleAll` dumps the role mapping for every
+    /// registered URL scheme and content type, so grepping that whole blob
+    /// would match any installed browser, not just the default one. Convert
+    /// the secure preferences plist to JSON instead and look up the one
+    /// entry whose `LSHandlerURLScheme` is `https`.
+    #[cfg(target_os = "macos")]
+    fn macos_https_handler() -> Option<String> {
         let home = std::env::var("HOME").ok()?;
+        let plist_path = format!(
+            "{}/Library/Preferences/com.apple.LaunchServices/com.apple.launchservices.secure.plist",
+            home
+        );
+
+        let output = Command::new("plutil")
+            .args(["-convert", "json", "-o", "-", &plist_path])
+            .output()
+            .ok()?;
+        let json: Value = serde_json::from_slice(&output.stdout).ok()?;
+
+        json.get("LSHandlers")?
+            .as_array()?
+            .iter()
+            .find(|handler| {
+                handler.get("LSHandlerURLScheme").and_then(|s| s.as_str()) == Some("https")
+            })?
+            .get("LSHandlerRoleAll")
+            .and_then(|role| role.as_str())
+            .map(|role| role.to_lowercase())
+    }
+
+    #[cfg(target_os = "windows")]
+    pub fn detect() -> Self {
+        // Registry-based probe for the UserChoice ProgId of the "https" association.
+        if let Ok(output) = Command::new("reg")
+            .args([
+                "query",
+                r"HKCU\Software\Microsoft\Windows\Shell\Associations\UrlAssociations\https\UserChoice",
+                "/v",
+                "ProgId",
+            ])
+            .output()
+        {
+            let browser_str = String::from_utf8_lossy(&output.stdout).to_lowercase();
+            let detected = Self::from_default_browser_str(&browser_str);
+            if !matches!(detected, Browser::Unknown) {
+                return detected;
+            }
+        }
+
+        Browser::Unknown
+    }
+
+    #[cfg(not(any(target_os = "linux", target_os = "macos", target_os = "windows")))]
+    pub fn detect() -> Self {
+        Browser::Unknown
+    }
+
+    /// Candidate bookmark file locations for this browser on the current
+    /// platform, in the order they should be tried.
+    #[cfg(target_os = "linux")]
+    pub fn bookmark_path(&self) -> Vec<PathBuf> {
+        let Some(home) = std::env::var("HOME").ok() else {
+            return Vec::new();
+        };
 
         match self {
-            Browser::Chrome => Some(PathBuf::from(format!(
+            Browser::Chrome => vec![PathBuf::from(format!(
                 "{}/.config/google-chrome/Default/Bookmarks",
                 home
-            ))),
-            Browser::Brave => Some(PathBuf::from(format!(
+            ))],
+            Browser::Brave => vec![PathBuf::from(format!(
                 "{}/.config/BraveSoftware/Brave-Browser/Default/Bookmarks",
                 home
-            ))),
-            Browser::Edge => Some(PathBuf::from(format!(
+            ))],
+            Browser::Edge => vec![PathBuf::from(format!(
                 "{}/.config/microsoft-edge/Default/Bookmarks",
                 home
-            ))),
-            Browser::Firefox | Browser::Zen => Some(PathBuf::from(home)),
-            Browser::Unknown => None,
+            ))],
+            // Firefox/Zen bookmarks live in a sqlite profile, resolved
+            // separately by `find_firefox_profile`, not a single file path.
+            Browser::Firefox | Browser::Zen | Browser::Unknown => Vec::new(),
+        }
+    }
+
+    #[cfg(target_os = "macos")]
+    pub fn bookmark_path(&self) -> Vec<PathBuf> {
+        let Some(home) = std::env::var("HOME").ok() else {
+            return Vec::new();
+        };
+
+        match self {
+            Browser::Chrome => vec![PathBuf::from(format!(
+                "{}/Library/Application Support/Google/Chrome/Default/Bookmarks",
+                home
+            ))],
+            Browser::Brave => vec![PathBuf::from(format!(
+                "{}/Library/Application Support/BraveSoftware/Brave-Browser/Default/Bookmarks",
+                home
+            ))],
+            Browser::Edge => vec![PathBuf::from(format!(
+                "{}/Library/Application Support/Microsoft Edge/Default/Bookmarks",
+                home
+            ))],
+            Browser::Firefox | Browser::Zen | Browser::Unknown => Vec::new(),
+        }
+    }
+
+    #[cfg(target_os = "windows")]
+    pub fn bookmark_path(&self) -> Vec<PathBuf> {
+        let local_app_data = std::env::var("LOCALAPPDATA").ok();
+
+        match self {
+            Browser::Chrome => local_app_data
+                .map(|d| vec![PathBuf::from(format!(r"{}\Google\Chrome\User Data\Default\Bookmarks", d))])
+                .unwrap_or_default(),
+            Browser::Brave => local_app_data
+                .map(|d| {
+                    vec![PathBuf::from(format!(
+                        r"{}\BraveSoftware\Brave-Browser\User Data\Default\Bookmarks",
+                        d
+                    ))]
+                })
+                .unwrap_or_default(),
+            Browser::Edge => local_app_data
+                .map(|d| vec![PathBuf::from(format!(r"{}\Microsoft\Edge\User Data\Default\Bookmarks", d))])
+                .unwrap_or_default(),
+            Browser::Firefox | Browser::Zen | Browser::Unknown => Vec::new(),
         }
     }
+
+    #[cfg(not(any(target_os = "linux", target_os = "macos", target_os = "windows")))]
+    pub fn bookmark_path(&self) -> Vec<PathBuf> {
+        Vec::new()
+    }
 }
 
 //PARSING
 pub fn parse_chromium_bookmarks(
     path: &PathBuf,
-) -> Result<Vec<Bookmark>, Box<dyn std::error::Error>> {
+) -> Result<Vec<BookmarkTree>, Box<dyn std::error::Error>> {
     let content = std::fs::read_to_string(path)?;
     let json: Value = serde_json::from_str(&content)?;
 
-    let mut bookmarks = Vec::new();
+    let mut roots = Vec::new();
 
     // Chrome bookmarks have a "roots" object with bookmark_bar, other, synced
-    if let Some(roots) = json.get("roots") {
-        if let Some(bookmark_bar) = roots.get("bookmark_bar") {
-            extract_bookmarks(bookmark_bar, &mut bookmarks);
-        }
-        if let Some(other) = roots.get("other") {
-            extract_bookmarks(other, &mut bookmarks);
-        }
-        if let Some(synced) = roots.get("synced") {
-            extract_bookmarks(synced, &mut bookmarks);
+    if let Some(roots_val) = json.get("roots") {
+        for key in ["bookmark_bar", "other", "synced"] {
+            if let Some(root) = roots_val.get(key) {
+                if let Some(tree) = extract_bookmarks(root) {
+                    roots.push(tree);
+                }
+            }
         }
     }
 
-    Ok(bookmarks)
+    Ok(roots)
 }
 
-fn extract_bookmarks(node: &Value, bookmarks: &mut Vec<Bookmark>) {
-    if let Some(node_type) = node.get("type").and_then(|t| t.as_str()) {
-        match node_type {
-            "url" => {
-                if let (Some(name), Some(url)) = (
-                    node.get("name").and_then(|n| n.as_str()),
-                    node.get("url").and_then(|u| u.as_str()),
-                ) {
-                    bookmarks.push(Bookmark {
-                        name: name.to_string(),
-                        url: url.to_string(),
-                    });
-                }
-            }
-            "folder" => {
-                if let Some(children) = node.get("children").and_then(|c| c.as_array()) {
-                    for child in children {
-                        extract_bookmarks(child, bookmarks);
-                    }
-                }
-            }
-            _ => {}
+/// Recursively builds a `BookmarkTree`, nesting each folder's children
+/// inside it so each bookmark ends up under its real folder path instead
+/// of flattened into one list.
+fn extract_bookmarks(node: &Value) -> Option<BookmarkTree> {
+    let node_type = node.get("type").and_then(|t| t.as_str())?;
+
+    match node_type {
+        "url" => {
+            let name = node.get("name").and_then(|n| n.as_str())?.to_string();
+            let url = node.get("url").and_then(|u| u.as_str())?.to_string();
+            let date_added = node
+                .get("date_added")
+                .and_then(|d| d.as_str())
+                .and_then(chrome_timestamp_to_unix);
+
+            Some(BookmarkTree::Bookmark(BookmarkNode {
+                name,
+                url,
+                tags: Vec::new(),
+                date_added,
+            }))
         }
+        "folder" => {
+            let title = node
+                .get("name")
+                .and_then(|n| n.as_str())
+                .unwrap_or("Untitled Folder")
+                .to_string();
+
+            let children = node
+                .get("children")
+                .and_then(|c| c.as_array())
+                .map(|arr| arr.iter().filter_map(extract_bookmarks).collect())
+                .unwrap_or_default();
+
+            Some(BookmarkTree::Folder(FolderNode { title, children }))
+        }
+        _ => None,
     }
 }
 
-pub fn get_bookmarks() -> Result<Vec<Bookmark>, Box<dyn std::error::Error>> {
+/// Chrome stores `date_added` as microseconds since the Windows epoch
+/// (1601-01-01), not since the Unix epoch.
+fn chrome_timestamp_to_unix(raw: &str) -> Option<i64> {
+    const WINDOWS_TO_UNIX_EPOCH_MICROS: i64 = 11_644_473_600_000_000;
+    let micros: i64 = raw.parse().ok()?;
+    Some((micros - WINDOWS_TO_UNIX_EPOCH_MICROS) / 1_000_000)
+}
+
+pub fn get_bookmarks() -> Result<Vec<BookmarkTree>, Box<dyn std::error::Error>> {
     let browser = Browser::detect();
     println!("Detected browser: {:?}", browser);
 
     match browser {
         Browser::Chrome | Browser::Brave | Browser::Edge => {
-            let path = browser
-                .bookmark_path()
-                .ok_or("Could not determine bookmark path")?;
+            let candidates = browser.bookmark_path();
+            let path = candidates
+                .iter()
+                .find(|p| p.exists())
+                .ok_or_else(|| format!("No bookmark file found in {:?}", candidates))?;
 
-            if !path.exists() {
-                return Err(format!("Bookmark file not found at {:?}", path).into());
-            }
-
-            parse_chromium_bookmarks(&path)
+            parse_chromium_bookmarks(path)
         }
         Browser::Firefox | Browser::Zen => {
             let profile_path =
@@ -146,41 +352,160 @@ pub fn get_bookmarks() -> Result<Vec<Bookmark>, Box<dyn std::error::Error>> {
         Browser::Unknown => Err("Could not detect browser".into()),
     }
 }
-fn find_firefox_profile(browser: &Browser) -> Option<PathBuf> {
+/// One `[ProfileN]` entry from `profiles.ini`.
+#[derive(Debug, Clone)]
+pub struct FirefoxProfile {
+    pub name: String,
+    pub path: PathBuf,
+    pub is_default: bool,
+}
+
+/// A bare-bones INI reader: enough to handle `profiles.ini`/`installs.ini`,
+/// which are just `[Section]` headers followed by `key=value` lines.
+fn parse_ini(content: &str) -> Vec<(String, std::collections::HashMap<String, String>)> {
+    let mut sections = Vec::new();
+    let mut current: Option<(String, std::collections::HashMap<String, String>)> = None;
+
+    for line in content.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with(';') || line.starts_with('#') {
+            continue;
+        }
+
+        if line.starts_with('[') && line.ends_with(']') {
+            if let Some(section) = current.take() {
+                sections.push(section);
+            }
+            current = Some((line[1..line.len() - 1].to_string(), Default::default()));
+        } else if let Some((key, value)) = line.split_once('=') {
+            if let Some((_, entries)) = current.as_mut() {
+                entries.insert(key.trim().to_string(), value.trim().to_string());
+            }
+        }
+    }
+
+    if let Some(section) = current.take() {
+        sections.push(section);
+    }
+
+    sections
+}
+
+#[cfg(target_os = "linux")]
+fn firefox_profile_dir(browser: &Browser) -> Option<PathBuf> {
     let home = std::env::var("HOME").ok()?;
+    match browser {
+        Browser::Zen => Some(PathBuf::from(format!("{}/.zen", home))),
+        Browser::Firefox => Some(PathBuf::from(format!("{}/.mozilla/firefox", home))),
+        _ => None,
+    }
+}
 
-    let profile_dir = match browser {
-        Browser::Zen => PathBuf::from(format!("{}/.zen", home)),
-        Browser::Firefox => PathBuf::from(format!("{}/.mozilla/firefox", home)),
-        _ => return None,
-    };
+#[cfg(target_os = "macos")]
+fn firefox_profile_dir(browser: &Browser) -> Option<PathBuf> {
+    let home = std::env::var("HOME").ok()?;
+    match browser {
+        Browser::Zen => Some(PathBuf::from(format!(
+            "{}/Library/Application Support/zen",
+            home
+        ))),
+        Browser::Firefox => Some(PathBuf::from(format!(
+            "{}/Library/Application Support/Firefox",
+            home
+        ))),
+        _ => None,
+    }
+}
 
-    // Read profiles.ini to find default profile
-    let profiles_ini = profile_dir.join("profiles.ini");
-    if !profiles_ini.exists() {
-        return None;
+#[cfg(target_os = "windows")]
+fn firefox_profile_dir(browser: &Browser) -> Option<PathBuf> {
+    let app_data = std::env::var("APPDATA").ok()?;
+    match browser {
+        Browser::Zen => Some(PathBuf::from(format!(r"{}\zen", app_data))),
+        Browser::Firefox => Some(PathBuf::from(format!(r"{}\Mozilla\Firefox", app_data))),
+        _ => None,
     }
+}
 
-    let content = fs::read_to_string(&profiles_ini).ok()?;
+#[cfg(not(any(target_os = "linux", target_os = "macos", target_os = "windows")))]
+fn firefox_profile_dir(_browser: &Browser) -> Option<PathBuf> {
+    None
+}
 
-    // Look for Default=1 profile or first profile with Path=
-    let mut default_path = None;
-    for line in content.lines() {
-        if line.starts_with("Path=") {
-            default_path = Some(line.trim_start_matches("Path=").to_string());
+/// All profiles declared in `profiles.ini`, in file order.
+pub fn list_firefox_profiles(browser: &Browser) -> Vec<FirefoxProfile> {
+    let Some(profile_dir) = firefox_profile_dir(browser) else {
+        return Vec::new();
+    };
+    let Ok(content) = fs::read_to_string(profile_dir.join("profiles.ini")) else {
+        return Vec::new();
+    };
+
+    parse_ini(&content)
+        .into_iter()
+        .filter(|(name, _)| name.starts_with("Profile"))
+        .filter_map(|(_, entries)| {
+            let path_value = entries.get("Path")?;
+            let is_relative = entries.get("IsRelative").map(|v| v == "1").unwrap_or(true);
+            let path = if is_relative {
+                profile_dir.join(path_value)
+            } else {
+                PathBuf::from(path_value)
+            };
+
+            Some(FirefoxProfile {
+                name: entries
+                    .get("Name")
+                    .cloned()
+                    .unwrap_or_else(|| path_value.clone()),
+                path,
+                is_default: entries.get("Default").map(|v| v == "1").unwrap_or(false),
+            })
+        })
+        .collect()
+}
+
+/// Picks the profile a given Firefox/Zen install actually launches.
+///
+/// Resolution order: `BOOKMARK_ANALYZER_PROFILE` (by profile name, falling
+/// back to a literal path) takes precedence, then the install-specific
+/// default recorded in `installs.ini`, then the `Default=1` profile in
+/// `profiles.ini`, then simply the first declared profile.
+fn find_firefox_profile(browser: &Browser) -> Option<PathBuf> {
+    let profile_dir = firefox_profile_dir(browser)?;
+    let profiles = list_firefox_profiles(browser);
+
+    if let Ok(wanted) = std::env::var("BOOKMARK_ANALYZER_PROFILE") {
+        if let Some(profile) = profiles.iter().find(|p| p.name == wanted) {
+            return Some(profile.path.clone());
+        }
+        let as_path = PathBuf::from(&wanted);
+        if as_path.exists() {
+            return Some(as_path);
         }
     }
 
-    if let Some(path) = default_path {
-        Some(profile_dir.join(path))
-    } else {
-        None
+    // Real installs.ini files key each section by a bare install hash (e.g.
+    // `[0BF4D082DA1580DB]`), not a literal "Install" prefix, so just check
+    // every section for a `Default=` entry rather than filtering by name.
+    if let Ok(installs_content) = fs::read_to_string(profile_dir.join("installs.ini")) {
+        for (_, entries) in parse_ini(&installs_content) {
+            if let Some(default_path) = entries.get("Default") {
+                return Some(profile_dir.join(default_path));
+            }
+        }
     }
+
+    profiles
+        .iter()
+        .find(|p| p.is_default)
+        .or_else(|| profiles.first())
+        .map(|p| p.path.clone())
 }
 
 pub fn parse_firefox_bookmarks(
-    profile_path: &PathBuf,
-) -> Result<Vec<Bookmark>, Box<dyn std::error::Error>> {
+    profile_path: &Path,
+) -> Result<Vec<BookmarkTree>, Box<dyn std::error::Error>> {
     let places_db = profile_path.join("places.sqlite");
 
     if !places_db.exists() {
@@ -193,25 +518,127 @@ pub fn parse_firefox_bookmarks(
 
     let conn = Connection::open(&temp_db)?;
 
+    let roots = root_folders(&conn)?;
+    let mut trees = Vec::with_capacity(roots.len());
+    for (title, folder_id) in roots {
+        trees.push(BookmarkTree::Folder(build_firefox_folder(
+            &conn, folder_id, title,
+        )?));
+    }
+
+    // Clean up temp file
+    let _ = fs::remove_file(temp_db);
+
+    Ok(trees)
+}
+
+/// The top-level folders (menu, toolbar, unfiled) that `places.sqlite`
+/// records in `moz_bookmarks_roots`, excluding the synthetic "tags" root.
+fn root_folders(conn: &Connection) -> SqliteResult<Vec<(String, i64)>> {
     let mut stmt = conn.prepare(
-        "SELECT mb.title, mp.url 
-         FROM moz_bookmarks mb 
-         JOIN moz_places mp ON mb.fk = mp.id 
-         WHERE mb.type = 1 AND mp.url IS NOT NULL",
+        "SELECT root_name, folder_id FROM moz_bookmarks_roots
+         WHERE root_name IN ('menu', 'toolbar', 'unfiled')",
     )?;
 
-    let bookmarks = stmt
+    let roots = stmt
         .query_map([], |row| {
-            Ok(Bookmark {
-                name: row.get(0).unwrap_or_else(|_| String::from("Untitled")),
-                url: row.get(1)?,
-            })
+            let root_name: String = row.get(0)?;
+            let title = match root_name.as_str() {
+                "menu" => "Bookmarks Menu",
+                "toolbar" => "Bookmarks Toolbar",
+                "unfiled" => "Other Bookmarks",
+                other => other,
+            };
+            Ok((title.to_string(), row.get::<_, i64>(1)?))
         })?
-        .filter_map(|r| r.ok())
         .collect();
+    roots
+}
 
-    // Clean up temp file
-    let _ = fs::remove_file(temp_db);
+/// One `moz_bookmarks` row: `(id, type, fk, title, dateAdded)`.
+type MozBookmarkRow = (i64, i64, Option<i64>, Option<String>, Option<i64>);
+
+/// Recursively walks `moz_bookmarks` by `parent`/`position` to rebuild the
+/// folder hierarchy under `folder_id`.
+fn build_firefox_folder(
+    conn: &Connection,
+    folder_id: i64,
+    title: String,
+) -> SqliteResult<FolderNode> {
+    let mut stmt = conn.prepare(
+        "SELECT id, type, fk, title, dateAdded
+         FROM moz_bookmarks
+         WHERE parent = ?1
+         ORDER BY position",
+    )?;
+
+    let entries: Vec<MozBookmarkRow> = stmt
+        .query_map([folder_id], |row| {
+            Ok((
+                row.get(0)?,
+                row.get(1)?,
+                row.get(2)?,
+                row.get(3)?,
+                row.get(4)?,
+            ))
+        })?
+        .collect::<SqliteResult<Vec<_>>>()?;
+
+    let mut children = Vec::new();
+    for (id, node_type, fk, node_title, date_added) in entries {
+        match node_type {
+            1 => {
+                // Bookmark: type = 1, `fk` points at the moz_places row.
+                let Some(place_id) = fk else { continue };
+                let url: Option<String> = conn
+                    .query_row("SELECT url FROM moz_places WHERE id = ?1", [place_id], |r| {
+                        r.get(0)
+                    })
+                    .ok();
+                let Some(url) = url else { continue };
+
+                children.push(BookmarkTree::Bookmark(BookmarkNode {
+                    name: node_title.unwrap_or_else(|| String::from("Untitled")),
+                    url,
+                    tags: tags_for_place(conn, place_id)?,
+                    date_added: date_added.map(prtime_to_unix),
+                }));
+            }
+            2 => {
+                // Folder: type = 2, recurse into its children.
+                let folder_title = node_title.unwrap_or_else(|| String::from("Untitled Folder"));
+                children.push(BookmarkTree::Folder(build_firefox_folder(
+                    conn,
+                    id,
+                    folder_title,
+                )?));
+            }
+            _ => {}
+        }
+    }
+
+    Ok(FolderNode { title, children })
+}
+
+/// Tags live as folders under the "tags" root; a tagged place shows up as a
+/// bookmark (`fk` = place id) inside the tag folder named after the tag.
+fn tags_for_place(conn: &Connection, place_id: i64) -> SqliteResult<Vec<String>> {
+    let mut stmt = conn.prepare(
+        "SELECT tag_folder.title
+         FROM moz_bookmarks tag_item
+         JOIN moz_bookmarks tag_folder ON tag_folder.id = tag_item.parent
+         JOIN moz_bookmarks_roots tags_root
+           ON tags_root.folder_id = tag_folder.parent AND tags_root.root_name = 'tags'
+         WHERE tag_item.fk = ?1",
+    )?;
+
+    let tags = stmt
+        .query_map([place_id], |row| row.get::<_, String>(0))?
+        .collect();
+    tags
+}
 
-    Ok(bookmarks)
+/// Firefox stores `dateAdded` as PRTime: microseconds since the Unix epoch.
+fn prtime_to_unix(prtime_micros: i64) -> i64 {
+    prtime_micros / 1_000_000
 }
@@ -0,0 +1,82 @@
+use serde::Deserialize;
+use std::path::PathBuf;
+
+/// Which `LlmBackend` implementation to talk to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum BackendKind {
+    #[default]
+    Ollama,
+    OpenaiCompatible,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    pub backend: BackendKind,
+    pub model: String,
+    pub base_url: String,
+    pub temperature: f32,
+    pub prompt_template: Option<String>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            backend: BackendKind::Ollama,
+            model: "llama3.2".to_string(),
+            base_url: "http://localhost:11434".to_string(),
+            temperature: 0.8,
+            prompt_template: None,
+        }
+    }
+}
+
+impl Config {
+    /// Loads the TOML file at `BOOKMARK_ANALYZER_CONFIG` (default
+    /// `~/.config/bookmark-analyzer/config.toml`) if it exists, then overlays
+    /// `BOOKMARK_ANALYZER_*` environment variables, which always win.
+    pub fn load() -> Self {
+        let mut config = Self::from_file().unwrap_or_default();
+
+        if let Ok(backend) = std::env::var("BOOKMARK_ANALYZER_BACKEND") {
+            config.backend = match backend.to_lowercase().as_str() {
+                "openai" | "openai-compatible" => BackendKind::OpenaiCompatible,
+                _ => BackendKind::Ollama,
+            };
+        }
+        if let Ok(model) = std::env::var("BOOKMARK_ANALYZER_MODEL") {
+            config.model = model;
+        }
+        if let Ok(base_url) = std::env::var("BOOKMARK_ANALYZER_BASE_URL") {
+            config.base_url = base_url;
+        }
+        if let Ok(temperature) = std::env::var("BOOKMARK_ANALYZER_TEMPERATURE") {
+            if let Ok(parsed) = temperature.parse() {
+                config.temperature = parsed;
+            }
+        }
+        if let Ok(template) = std::env::var("BOOKMARK_ANALYZER_PROMPT_TEMPLATE") {
+            config.prompt_template = Some(template);
+        }
+
+        config
+    }
+
+    fn from_file() -> Option<Self> {
+        let content = std::fs::read_to_string(Self::config_path()?).ok()?;
+        toml::from_str(&content).ok()
+    }
+
+    fn config_path() -> Option<PathBuf> {
+        if let Ok(path) = std::env::var("BOOKMARK_ANALYZER_CONFIG") {
+            return Some(PathBuf::from(path));
+        }
+
+        let home = std::env::var("HOME").ok()?;
+        Some(PathBuf::from(format!(
+            "{}/.config/bookmark-analyzer/config.toml",
+            home
+        )))
+    }
+}
@@ -0,0 +1,118 @@
+use crate::browser::{BookmarkNode, BookmarkTree};
+
+/// Converts the parsed bookmark tree into an RSS 2.0 feed so it can be
+/// subscribed to from any feed reader, mirroring how bookmark-to-RSS
+/// bridges turn saved links into feed `Item`s.
+pub fn build_rss(bookmarks: &[BookmarkTree]) -> String {
+    let mut items = String::new();
+    let mut path = Vec::new();
+    for tree in bookmarks {
+        collect_items(tree, &mut path, &mut items);
+    }
+
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<rss version="2.0">
+<channel>
+<title>My Bookmarks</title>
+<link>http://127.0.0.1:3000/</link>
+<description>Bookmarks collected by bookmark-analyzer</description>
+{}</channel>
+</rss>"#,
+        items
+    )
+}
+
+fn collect_items(tree: &BookmarkTree, path: &mut Vec<String>, items: &mut String) {
+    match tree {
+        BookmarkTree::Folder(folder) => {
+            path.push(folder.title.clone());
+            for child in &folder.children {
+                collect_items(child, path, items);
+            }
+            path.pop();
+        }
+        BookmarkTree::Bookmark(bookmark) => items.push_str(&render_item(bookmark, path)),
+    }
+}
+
+fn render_item(bookmark: &BookmarkNode, path: &[String]) -> String {
+    let mut categories = String::new();
+    if let Some(folder) = path.last() {
+        categories.push_str(&format!("<category>{}</category>\n", escape_xml(folder)));
+    }
+    for tag in &bookmark.tags {
+        categories.push_str(&format!("<category>{}</category>\n", escape_xml(tag)));
+    }
+
+    let pub_date = bookmark
+        .date_added
+        .map(|secs| format!("<pubDate>{}</pubDate>\n", format_rfc822(secs)))
+        .unwrap_or_default();
+
+    format!(
+        "<item>\n<title>{}</title>\n<link>{}</link>\n<guid>{}</guid>\n{}{}</item>\n",
+        escape_xml(&bookmark.name),
+        escape_xml(&bookmark.url),
+        escape_xml(&bookmark.url),
+        categories,
+        pub_date
+    )
+}
+
+fn escape_xml(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Formats a Unix timestamp as an RFC 822 date, the format RSS `pubDate`
+/// requires. No date/time crate is in the dependency tree, so the calendar
+/// conversion is done by hand using Howard Hinnant's `civil_from_days`
+/// algorithm (the standard allocation-free days-since-epoch -> y/m/d trick).
+fn format_rfc822(unix_secs: i64) -> String {
+    let days = unix_secs.div_euclid(86400);
+    let secs_of_day = unix_secs.rem_euclid(86400);
+    let (year, month, day) = civil_from_days(days);
+    let hour = secs_of_day / 3600;
+    let minute = (secs_of_day % 3600) / 60;
+    let second = secs_of_day % 60;
+
+    format!(
+        "{}, {:02} {} {} {:02}:{:02}:{:02} GMT",
+        weekday_from_days(days),
+        day,
+        month_name(month),
+        year,
+        hour,
+        minute,
+        second
+    )
+}
+
+fn civil_from_days(z: i64) -> (i64, u32, u32) {
+    let z = z + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z.rem_euclid(146097); // [0, 146096]
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365; // [0, 399]
+    let y = yoe + era * 400;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100); // [0, 365]
+    let mp = (5 * doy + 2) / 153; // [0, 11]
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32; // [1, 31]
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32; // [1, 12]
+    let year = if month <= 2 { y + 1 } else { y };
+    (year, month, day)
+}
+
+fn weekday_from_days(z: i64) -> &'static str {
+    const DAYS: [&str; 7] = ["Thu", "Fri", "Sat", "Sun", "Mon", "Tue", "Wed"]; // z=0 (1970-01-01) was a Thursday
+    DAYS[z.rem_euclid(7) as usize]
+}
+
+fn month_name(m: u32) -> &'static str {
+    const MONTHS: [&str; 12] = [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ];
+    MONTHS[(m - 1) as usize]
+}
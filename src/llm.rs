@@ -0,0 +1,145 @@
+use crate::config::{BackendKind, Config};
+use futures_util::Stream;
+use std::pin::Pin;
+use std::sync::Arc;
+
+pub type TokenStream = Pin<Box<dyn Stream<Item = String> + Send>>;
+
+/// A pluggable chat/completion backend. `OllamaBackend` talks to a local
+/// Ollama daemon; `OpenAiBackend` talks to anything exposing an
+/// OpenAI-compatible `/v1/chat/completions` endpoint.
+#[async_trait::async_trait]
+pub trait LlmBackend: Send + Sync {
+    async fn generate_stream(&self, prompt: String)
+        -> Result<TokenStream, Box<dyn std::error::Error>>;
+}
+
+/// Builds the configured backend, reading the API key from secure storage
+/// when the backend needs one.
+pub fn from_config(config: &Config) -> Arc<dyn LlmBackend> {
+    match config.backend {
+        BackendKind::Ollama => Arc::new(crate::ollama::OllamaBackend::new(config)),
+        BackendKind::OpenaiCompatible => {
+            let api_key = crate::secrets::load_api_key().unwrap_or_default();
+            Arc::new(OpenAiBackend::new(config, api_key))
+        }
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+struct ChatMessage {
+    role: String,
+    content: String,
+}
+
+#[derive(Debug, serde::Serialize)]
+struct ChatRequest {
+    model: String,
+    messages: Vec<ChatMessage>,
+    temperature: f32,
+    stream: bool,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct ChatStreamChunk {
+    choices: Vec<ChatStreamChoice>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct ChatStreamChoice {
+    delta: ChatStreamDelta,
+}
+
+#[derive(Debug, Default, serde::Deserialize)]
+struct ChatStreamDelta {
+    #[serde(default)]
+    content: Option<String>,
+}
+
+pub struct OpenAiBackend {
+    base_url: String,
+    model: String,
+    temperature: f32,
+    api_key: String,
+}
+
+impl OpenAiBackend {
+    pub fn new(config: &Config, api_key: String) -> Self {
+        Self {
+            base_url: config.base_url.clone(),
+            model: config.model.clone(),
+            temperature: config.temperature,
+            api_key,
+        }
+    }
+
+    fn endpoint(&self) -> String {
+        format!("{}/v1/chat/completions", self.base_url.trim_end_matches('/'))
+    }
+
+    fn request(&self, prompt: String, stream: bool) -> ChatRequest {
+        ChatRequest {
+            model: self.model.clone(),
+            messages: vec![ChatMessage {
+                role: "user".to_string(),
+                content: prompt,
+            }],
+            temperature: self.temperature,
+            stream,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl LlmBackend for OpenAiBackend {
+    async fn generate_stream(
+        &self,
+        prompt: String,
+    ) -> Result<TokenStream, Box<dyn std::error::Error>> {
+        let client = reqwest::Client::new();
+        let response = client
+            .post(self.endpoint())
+            .bearer_auth(&self.api_key)
+            .json(&self.request(prompt, true))
+            .send()
+            .await?;
+
+        let stream = futures_util::stream::unfold(
+            (response, String::new()),
+            |(mut response, mut buf)| async move {
+                loop {
+                    if let Some(pos) = buf.find('\n') {
+                        let line: String = buf.drain(..=pos).collect();
+                        let line = line.trim().trim_start_matches("data:").trim();
+                        if line.is_empty() {
+                            continue;
+                        }
+                        if line == "[DONE]" {
+                            return None;
+                        }
+
+                        let Ok(chunk) = serde_json::from_str::<ChatStreamChunk>(line) else {
+                            continue;
+                        };
+                        let Some(content) = chunk
+                            .choices
+                            .into_iter()
+                            .next()
+                            .and_then(|choice| choice.delta.content)
+                        else {
+                            continue;
+                        };
+                        return Some((content, (response, buf)));
+                    }
+
+                    match response.chunk().await {
+                        Ok(Some(chunk)) => buf.push_str(&String::from_utf8_lossy(&chunk)),
+                        _ => return None,
+                    }
+                }
+            },
+        );
+
+        Ok(Box::pin(stream))
+    }
+}
@@ -1,14 +1,38 @@
 mod browser;
+mod config;
+mod feed;
+mod llm;
 mod ollama;
+mod prompt;
+mod secrets;
 mod server;
+mod store;
 
 #[tokio::main]
 async fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    if let Some(command) = args.first() {
+        if matches!(command.as_str(), "add" | "edit" | "remove" | "list") {
+            if let Err(e) = run_store_command(command, &args[1..]) {
+                eprintln!("❌ {}", e);
+            }
+            return;
+        }
+
+        if command == "set-key" {
+            if let Err(e) = run_set_key() {
+                eprintln!("❌ {}", e);
+            }
+            return;
+        }
+    }
+
     println!("🔍 Detecting browser and reading bookmarks...\n");
 
-    let bookmarks = match browser::get_bookmarks() {
+    let mut bookmarks = match browser::get_bookmarks() {
         Ok(bm) => {
-            println!("✅ Found {} bookmarks\n", bm.len());
+            println!("✅ Found {} bookmarks\n", browser::count_bookmarks(&bm));
             bm
         }
         Err(e) => {
@@ -17,24 +41,180 @@ async fn main() {
         }
     };
 
-    println!("🤖 Analyzing bookmarks with Ollama...");
-    println!("   (This may take a moment)\n");
+    match store::Store::open() {
+        Ok(store) => {
+            match store.import(&bookmarks) {
+                Ok(imported) if imported > 0 => {
+                    println!("📥 Saved {} new bookmarks to the local store\n", imported)
+                }
+                Ok(_) => {}
+                Err(e) => eprintln!("⚠️  Could not save bookmarks to the local store: {}", e),
+            }
 
-    let analysis = match ollama::analyze_bookmarks(&bookmarks).await {
-        Ok(result) => {
-            println!("✅ Analysis complete!\n");
-            result
-        }
-        Err(e) => {
-            eprintln!("❌ Error analyzing bookmarks: {}", e);
-            eprintln!("\n💡 Make sure Ollama is running:");
-            eprintln!("   docker-compose up -d");
-            eprintln!("   docker exec -it bookmark-analyzer-ollama-1 ollama pull llama3.2");
-            return;
+            match store.list() {
+                Ok(stored) => {
+                    store::apply_overrides(&mut bookmarks, &store::overrides_by_url(stored))
+                }
+                Err(e) => eprintln!("⚠️  Could not read stored bookmark metadata: {}", e),
+            }
         }
-    };
+        Err(e) => eprintln!("⚠️  Could not open the local bookmark store: {}", e),
+    }
+
+    let config = config::Config::load();
+    let backend = llm::from_config(&config);
+
+    println!(
+        "🤖 Using {:?} ({}) — the reflection generates on first page load.",
+        config.backend, config.model
+    );
+    println!("   backend:  {:?}", config.backend);
+    println!("   base_url: {}", config.base_url);
+    println!("   model:    {}", config.model);
+    if matches!(config.backend, config::BackendKind::OpenaiCompatible) {
+        println!("   (if generation fails, check that an API key is stored — see secrets::store_api_key)");
+    }
+    println!();
 
-    if let Err(e) = server::serve(analysis, bookmarks.len()).await {
+    if let Err(e) = server::serve(bookmarks, backend, config.prompt_template).await {
         eprintln!("Server error: {}", e);
     }
 }
+
+/// Drives the `add`/`edit`/`remove`/`list` subcommands against the local
+/// bookmark store, prompting on stdin for whichever fields a command needs.
+fn run_store_command(command: &str, args: &[String]) -> Result<(), Box<dyn std::error::Error>> {
+    let store = store::Store::open()?;
+
+    match command {
+        "list" => {
+            for bookmark in store.list()? {
+                let label = bookmark.custom_name.as_deref().unwrap_or(&bookmark.name);
+                println!("{} — {}", label, bookmark.url);
+                if !bookmark.tags.is_empty() {
+                    println!("    tags: {}", bookmark.tags.join(", "));
+                }
+                if let Some(description) = &bookmark.description {
+                    println!("    {}", description);
+                }
+            }
+        }
+        "add" => {
+            let url = args
+                .first()
+                .cloned()
+                .ok_or("usage: bookmark-analyzer add <url>")?;
+            let custom_name = prompt_line("Display name (blank to derive from the URL): ")?;
+            let description = prompt_line("Description (optional): ")?;
+            let tags = prompt_line("Tags, comma separated (optional): ")?;
+
+            store.add(
+                &url,
+                non_empty(&custom_name),
+                non_empty(&description),
+                &split_tags(&tags),
+            )?;
+            println!("Added {}", url);
+        }
+        "edit" => {
+            let url = select_bookmark(&store, args.first().map(String::as_str))?;
+            let custom_name = prompt_line("New display name (blank to keep current): ")?;
+            let description = prompt_line("New description (blank to keep current): ")?;
+            let tags = prompt_line("New tags, comma separated (blank to keep current): ")?;
+            let tags = if tags.trim().is_empty() {
+                None
+            } else {
+                Some(split_tags(&tags))
+            };
+
+            if store.edit(&url, non_empty(&custom_name), non_empty(&description), tags.as_deref())? {
+                println!("Updated {}", url);
+            } else {
+                println!("No bookmark stored for {}", url);
+            }
+        }
+        "remove" => {
+            let url = select_bookmark(&store, args.first().map(String::as_str))?;
+            if store.remove(&url)? {
+                println!("Removed {}", url);
+            } else {
+                println!("No bookmark stored for {}", url);
+            }
+        }
+        other => return Err(format!("unknown subcommand: {}", other).into()),
+    }
+
+    Ok(())
+}
+
+/// Prompts for the remote backend's API key and stores it via
+/// `secrets::store_api_key`, so a user configuring an OpenAI-compatible
+/// backend never has to put the key in plaintext config or an env var.
+fn run_set_key() -> Result<(), Box<dyn std::error::Error>> {
+    let key = prompt_line("API key: ")?;
+    if key.is_empty() {
+        return Err("no key entered".into());
+    }
+
+    secrets::store_api_key(&key)?;
+    println!("Stored the API key.");
+    Ok(())
+}
+
+/// Resolves the bookmark an `edit`/`remove` call should act on: the URL if
+/// one was passed directly, otherwise a numbered picker over everything in
+/// the store so the user doesn't have to retype a URL by hand.
+fn select_bookmark(
+    store: &store::Store,
+    url: Option<&str>,
+) -> Result<String, Box<dyn std::error::Error>> {
+    if let Some(url) = url {
+        return Ok(url.to_string());
+    }
+
+    let stored = store.list()?;
+    if stored.is_empty() {
+        return Err("no bookmarks in the local store".into());
+    }
+
+    for (i, bookmark) in stored.iter().enumerate() {
+        let label = bookmark.custom_name.as_deref().unwrap_or(&bookmark.name);
+        println!("{}) {} — {}", i + 1, label, bookmark.url);
+    }
+
+    let choice = prompt_line("Pick a bookmark by number: ")?;
+    let index: usize = choice
+        .trim()
+        .parse()
+        .ok()
+        .filter(|n| *n >= 1 && *n <= stored.len())
+        .ok_or("enter a number from the list above")?;
+
+    Ok(stored[index - 1].url.clone())
+}
+
+fn prompt_line(label: &str) -> Result<String, Box<dyn std::error::Error>> {
+    use std::io::Write;
+    print!("{}", label);
+    std::io::stdout().flush()?;
+
+    let mut line = String::new();
+    std::io::stdin().read_line(&mut line)?;
+    Ok(line.trim().to_string())
+}
+
+fn non_empty(value: &str) -> Option<&str> {
+    if value.is_empty() {
+        None
+    } else {
+        Some(value)
+    }
+}
+
+fn split_tags(value: &str) -> Vec<String> {
+    value
+        .split(',')
+        .map(|t| t.trim().to_string())
+        .filter(|t| !t.is_empty())
+        .collect()
+}
@@ -1,4 +1,5 @@
-use reqwest;
+use crate::config::Config;
+use crate::llm::{LlmBackend, TokenStream};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize)]
@@ -6,41 +7,96 @@ struct OllamaRequest {
     model: String,
     prompt: String,
     stream: bool,
+    options: OllamaOptions,
+}
+
+#[derive(Debug, Serialize)]
+struct OllamaOptions {
+    temperature: f32,
 }
 
 #[derive(Debug, Deserialize)]
 struct OllamaResponse {
     response: String,
+    #[serde(default)]
+    done: bool,
+}
+
+pub struct OllamaBackend {
+    base_url: String,
+    model: String,
+    temperature: f32,
+}
+
+impl OllamaBackend {
+    pub fn new(config: &Config) -> Self {
+        Self {
+            base_url: config.base_url.clone(),
+            model: config.model.clone(),
+            temperature: config.temperature,
+        }
+    }
+
+    fn endpoint(&self) -> String {
+        format!("{}/api/generate", self.base_url.trim_end_matches('/'))
+    }
+
+    fn request(&self, prompt: String, stream: bool) -> OllamaRequest {
+        OllamaRequest {
+            model: self.model.clone(),
+            prompt,
+            stream,
+            options: OllamaOptions {
+                temperature: self.temperature,
+            },
+        }
+    }
 }
 
-pub async fn analyze_bookmarks(
-    bookmarks: &[crate::browser::Bookmark],
-) -> Result<String, Box<dyn std::error::Error>> {
-    // Build the prompt
-    let bookmark_list: Vec<String> = bookmarks
-        .iter()
-        .map(|b| format!("- {}: {}", b.name, b.url))
-        .collect();
-
-    let prompt = format!(
-        "You are an insightful analyst. Based on someone's browser bookmarks, provide a thoughtful reflection about their interests, habits, and personality. Be creative and engaging.\n\nBookmarks:\n{}\n\nProvide a 2-3 paragraph reflection:",
-        bookmark_list.join("\n")
-    );
-
-    let client = reqwest::Client::new();
-    let request = OllamaRequest {
-        model: "llama3.2".to_string(),
-        prompt,
-        stream: false,
-    };
-
-    let response = client
-        .post("http://localhost:11434/api/generate")
-        .json(&request)
-        .send()
-        .await?;
-
-    let ollama_response: OllamaResponse = response.json().await?;
-
-    Ok(ollama_response.response)
+#[async_trait::async_trait]
+impl LlmBackend for OllamaBackend {
+    /// Ollama replies with newline-delimited JSON fragments when
+    /// `stream: true`; each fragment carries its own `response` piece and a
+    /// `done` flag marking the final one.
+    async fn generate_stream(
+        &self,
+        prompt: String,
+    ) -> Result<TokenStream, Box<dyn std::error::Error>> {
+        let client = reqwest::Client::new();
+        let response = client
+            .post(self.endpoint())
+            .json(&self.request(prompt, true))
+            .send()
+            .await?;
+
+        let stream = futures_util::stream::unfold(
+            (response, String::new()),
+            |(mut response, mut buf)| async move {
+                loop {
+                    if let Some(pos) = buf.find('\n') {
+                        let line: String = buf.drain(..=pos).collect();
+                        let line = line.trim();
+                        if line.is_empty() {
+                            continue;
+                        }
+
+                        let Ok(fragment) = serde_json::from_str::<OllamaResponse>(line) else {
+                            continue;
+                        };
+                        if fragment.done {
+                            return None;
+                        }
+                        return Some((fragment.response, (response, buf)));
+                    }
+
+                    match response.chunk().await {
+                        Ok(Some(chunk)) => buf.push_str(&String::from_utf8_lossy(&chunk)),
+                        _ => return None,
+                    }
+                }
+            },
+        );
+
+        Ok(Box::pin(stream))
+    }
 }
@@ -0,0 +1,43 @@
+use crate::browser::BookmarkTree;
+
+const DEFAULT_TEMPLATE: &str = "You are an insightful analyst. Based on someone's browser bookmarks — including how they organize them into folders and tag them — provide a thoughtful reflection about their interests, habits, and personality. Be creative and engaging.\n\nBookmarks:\n{bookmarks}\n\nProvide a 2-3 paragraph reflection:";
+
+/// Builds the analysis prompt, preserving folder grouping and tags so the
+/// model can reason about organization habits, not just raw link titles.
+/// `template`, when given, replaces the default wording; either way the
+/// literal `{bookmarks}` placeholder is substituted with the rendered tree.
+pub fn build_prompt(bookmarks: &[BookmarkTree], template: Option<&str>) -> String {
+    let mut lines = Vec::new();
+    for tree in bookmarks {
+        render_tree(tree, 0, &mut lines);
+    }
+
+    template
+        .unwrap_or(DEFAULT_TEMPLATE)
+        .replace("{bookmarks}", &lines.join("\n"))
+}
+
+/// Renders a `BookmarkTree` as indented lines, one folder heading or
+/// bookmark per line, so the prompt reflects the user's own grouping.
+fn render_tree(tree: &BookmarkTree, depth: usize, lines: &mut Vec<String>) {
+    let indent = "  ".repeat(depth);
+    match tree {
+        BookmarkTree::Folder(folder) => {
+            lines.push(format!("{}- {}/", indent, folder.title));
+            for child in &folder.children {
+                render_tree(child, depth + 1, lines);
+            }
+        }
+        BookmarkTree::Bookmark(bookmark) => {
+            let tags = if bookmark.tags.is_empty() {
+                String::new()
+            } else {
+                format!(" [tags: {}]", bookmark.tags.join(", "))
+            };
+            lines.push(format!(
+                "{}- {}: {}{}",
+                indent, bookmark.name, bookmark.url, tags
+            ));
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use std::path::PathBuf;
+
+const KEYRING_SERVICE: &str = "bookmark-analyzer";
+const KEYRING_USER: &str = "llm-api-key";
+
+/// Reads the remote backend's API key from the OS keyring, falling back to
+/// a plain file under `~/.config/bookmark-analyzer/` on platforms without a
+/// keyring daemon (e.g. headless Linux), mirroring how secure-transfer tools
+/// degrade gracefully when the platform keystore isn't available.
+pub fn load_api_key() -> Option<String> {
+    if let Ok(entry) = keyring::Entry::new(KEYRING_SERVICE, KEYRING_USER) {
+        if let Ok(secret) = entry.get_password() {
+            return Some(secret);
+        }
+    }
+
+    std::fs::read_to_string(fallback_key_path()?)
+        .ok()
+        .map(|s| s.trim().to_string())
+}
+
+/// Stores the API key, preferring the OS keyring and only writing a
+/// world-unreadable fallback file when no keyring is available.
+pub fn store_api_key(key: &str) -> Result<(), Box<dyn std::error::Error>> {
+    if let Ok(entry) = keyring::Entry::new(KEYRING_SERVICE, KEYRING_USER) {
+        if entry.set_password(key).is_ok() {
+            return Ok(());
+        }
+    }
+
+    let path = fallback_key_path().ok_or("Could not determine a fallback key file path")?;
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(&path, key)?;
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o600))?;
+    }
+
+    Ok(())
+}
+
+fn fallback_key_path() -> Option<PathBuf> {
+    let home = std::env::var("HOME").ok()?;
+    Some(PathBuf::from(format!(
+        "{}/.config/bookmark-analyzer/api_key",
+        home
+    )))
+}
@@ -0,0 +1,186 @@
+use axum::{
+    extract::State,
+    response::{
+        sse::{Event, Sse},
+        Html, IntoResponse,
+    },
+    routing::get,
+    Router,
+};
+use futures_util::{stream, Stream, StreamExt};
+use std::convert::Infallible;
+use std::pin::Pin;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+/// An event stream the `/stream` route can hand to `Sse`, boxed because the
+/// live-generation and cached-replay paths aren't the same concrete type.
+type EventStream = Pin<Box<dyn Stream<Item = Result<Event, Infallible>> + Send>>;
+
+#[derive(Clone)]
+struct AppState {
+    /// The reflection text, generated lazily by a `/stream` request and
+    /// cached here once that generation has run to completion, so later
+    /// page loads replay it instead of paying for another LLM completion.
+    analysis: Arc<Mutex<Option<String>>>,
+    bookmarks: Arc<Vec<crate::browser::BookmarkTree>>,
+    backend: Arc<dyn crate::llm::LlmBackend>,
+    prompt_template: Option<Arc<String>>,
+}
+
+pub async fn serve(
+    bookmarks: Vec<crate::browser::BookmarkTree>,
+    backend: Arc<dyn crate::llm::LlmBackend>,
+    prompt_template: Option<String>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let state = AppState {
+        analysis: Arc::new(Mutex::new(None)),
+        bookmarks: Arc::new(bookmarks),
+        backend,
+        prompt_template: prompt_template.map(Arc::new),
+    };
+
+    let app = Router::new()
+        .route("/", get(index))
+        .route("/stream", get(stream_analysis))
+        .route("/feed.xml", get(feed))
+        .with_state(state);
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:3000").await?;
+    println!("🌐 Serving your reflection at http://127.0.0.1:3000");
+    axum::serve(listener, app).await?;
+
+    Ok(())
+}
+
+async fn index(State(state): State<AppState>) -> impl IntoResponse {
+    let count = crate::browser::count_bookmarks(&state.bookmarks);
+
+    Html(format!(
+        r#"<html><body>
+<h1>{} bookmarks, reflected</h1>
+<p><a href="/feed.xml">Subscribe to your bookmarks as RSS</a></p>
+<div id="reflection"></div>
+<script>
+  if (!!window.EventSource) {{
+    const target = document.getElementById('reflection');
+    const source = new EventSource('/stream');
+    source.onmessage = (e) => {{ target.textContent += e.data; }};
+    source.addEventListener('done', () => source.close());
+  }}
+</script>
+</body></html>"#,
+        count
+    ))
+}
+
+/// Serves the reflection over SSE: a request that finds nothing cached
+/// generates it from the configured backend and caches the result once the
+/// generation finishes; every request after that (a page refresh, a second
+/// tab) replays that cached text instead of running another —
+/// non-deterministic, potentially billed — completion. A request that
+/// arrives while a generation is still running, or after one was cut off
+/// or failed, finds no cache and generates again.
+///
+/// Both paths end with a `done` event so the client can close its
+/// `EventSource` explicitly — without it, the browser treats the closed
+/// connection as a drop and auto-reconnects, re-running this handler forever.
+async fn stream_analysis(State(state): State<AppState>) -> Sse<EventStream> {
+    let cached = state.analysis.lock().await.clone();
+
+    let stream: EventStream = match cached {
+        Some(text) => Box::pin(replay_stream(text)),
+        None => {
+            let prompt = crate::prompt::build_prompt(
+                &state.bookmarks,
+                state.prompt_template.as_deref().map(String::as_str),
+            );
+
+            match state.backend.generate_stream(prompt).await {
+                Ok(tokens) => Box::pin(live_stream(tokens, state.analysis.clone())),
+                Err(e) => {
+                    let message = format!("Error generating reflection: {}", e);
+                    Box::pin(
+                        stream::once(async move { Ok(Event::default().data(message)) })
+                            .chain(done_event()),
+                    )
+                }
+            }
+        }
+    };
+
+    Sse::new(stream)
+}
+
+/// Forwards the backend's tokens to the client as they arrive, accumulating
+/// them into `cache` so the next `/stream` call can replay instead of
+/// regenerating.
+fn live_stream(
+    tokens: crate::llm::TokenStream,
+    cache: Arc<Mutex<Option<String>>>,
+) -> impl Stream<Item = Result<Event, Infallible>> {
+    struct State {
+        tokens: crate::llm::TokenStream,
+        buffer: String,
+        cache: Arc<Mutex<Option<String>>>,
+        done: bool,
+    }
+
+    stream::unfold(
+        State {
+            tokens,
+            buffer: String::new(),
+            cache,
+            done: false,
+        },
+        |mut state| async move {
+            if state.done {
+                return None;
+            }
+
+            match state.tokens.next().await {
+                Some(text) => {
+                    state.buffer.push_str(&text);
+                    Some((Ok(Event::default().data(text)), state))
+                }
+                None => {
+                    *state.cache.lock().await = Some(std::mem::take(&mut state.buffer));
+                    state.done = true;
+                    Some((Ok(Event::default().event("done").data("")), state))
+                }
+            }
+        },
+    )
+}
+
+/// Replays already-generated text a word at a time with a small delay, so a
+/// repeat page load still reads like the reflection is being written rather
+/// than dumping the whole cached block at once.
+fn replay_stream(text: String) -> impl Stream<Item = Result<Event, Infallible>> {
+    let words: Vec<String> = text.split_inclusive(' ').map(str::to_string).collect();
+
+    stream::unfold(words.into_iter(), |mut words| async move {
+        match words.next() {
+            Some(word) => {
+                tokio::time::sleep(std::time::Duration::from_millis(15)).await;
+                Some((Ok(Event::default().data(word)), words))
+            }
+            None => None,
+        }
+    })
+    .chain(done_event())
+}
+
+fn done_event() -> impl Stream<Item = Result<Event, Infallible>> {
+    stream::once(async { Ok(Event::default().event("done").data("")) })
+}
+
+async fn feed(State(state): State<AppState>) -> impl IntoResponse {
+    (
+        [(
+            axum::http::header::CONTENT_TYPE,
+            "application/rss+xml; charset=utf-8",
+        )],
+        crate::feed::build_rss(&state.bookmarks),
+    )
+}
@@ -0,0 +1,247 @@
+use rusqlite::{params, Connection, Result as SqliteResult};
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use crate::browser::BookmarkTree;
+
+/// A bookmark's user-supplied metadata, persisted independently of whatever
+/// the browser itself reports, so it survives across re-imports.
+#[derive(Debug, Clone)]
+pub struct StoredBookmark {
+    pub url: String,
+    pub name: String,
+    pub custom_name: Option<String>,
+    pub description: Option<String>,
+    pub tags: Vec<String>,
+}
+
+pub struct Store {
+    conn: Connection,
+}
+
+impl Store {
+    pub fn open() -> Result<Self, Box<dyn std::error::Error>> {
+        let path = Self::db_path()?;
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        let conn = Connection::open(path)?;
+        conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS bookmarks (
+                url         TEXT PRIMARY KEY,
+                name        TEXT NOT NULL,
+                custom_name TEXT,
+                description TEXT,
+                tags        TEXT NOT NULL DEFAULT ''
+            );",
+        )?;
+
+        Ok(Self { conn })
+    }
+
+    fn db_path() -> Result<PathBuf, Box<dyn std::error::Error>> {
+        let home = std::env::var("HOME")?;
+        Ok(PathBuf::from(format!(
+            "{}/.config/bookmark-analyzer/store.sqlite",
+            home
+        )))
+    }
+
+    /// Records any freshly-read bookmark not already tracked, defaulting its
+    /// display name to a readable title derived from the URL when none is
+    /// given. Returns how many new rows were inserted.
+    pub fn import(&self, trees: &[BookmarkTree]) -> SqliteResult<usize> {
+        let mut flat = Vec::new();
+        for tree in trees {
+            tree.flatten(&mut flat);
+        }
+
+        let mut imported = 0;
+        for bookmark in flat {
+            let already_tracked: Option<String> = self
+                .conn
+                .query_row(
+                    "SELECT url FROM bookmarks WHERE url = ?1",
+                    params![bookmark.url],
+                    |row| row.get(0),
+                )
+                .ok();
+            if already_tracked.is_some() {
+                continue;
+            }
+
+            let name = if bookmark.name.trim().is_empty() {
+                readable_name_from_url(&bookmark.url)
+            } else {
+                bookmark.name.clone()
+            };
+
+            self.conn.execute(
+                "INSERT INTO bookmarks (url, name, tags) VALUES (?1, ?2, ?3)",
+                params![bookmark.url, name, bookmark.tags.join(",")],
+            )?;
+            imported += 1;
+        }
+
+        Ok(imported)
+    }
+
+    pub fn list(&self) -> SqliteResult<Vec<StoredBookmark>> {
+        let mut stmt = self
+            .conn
+            .prepare("SELECT url, name, custom_name, description, tags FROM bookmarks ORDER BY url")?;
+
+        let bookmarks = stmt
+            .query_map([], |row| {
+                let tags: String = row.get(4)?;
+                Ok(StoredBookmark {
+                    url: row.get(0)?,
+                    name: row.get(1)?,
+                    custom_name: row.get(2)?,
+                    description: row.get(3)?,
+                    tags: split_tags(&tags),
+                })
+            })?
+            .collect();
+        bookmarks
+    }
+
+    /// Adds a bookmark the user typed in by hand (rather than imported from
+    /// a browser). If one is already stored at that URL, its custom name,
+    /// description and tags are overwritten; its stored name is kept.
+    pub fn add(
+        &self,
+        url: &str,
+        custom_name: Option<&str>,
+        description: Option<&str>,
+        tags: &[String],
+    ) -> SqliteResult<()> {
+        let name = custom_name
+            .map(String::from)
+            .unwrap_or_else(|| readable_name_from_url(url));
+
+        self.conn.execute(
+            "INSERT INTO bookmarks (url, name, custom_name, description, tags)
+             VALUES (?1, ?2, ?3, ?4, ?5)
+             ON CONFLICT(url) DO UPDATE SET
+                custom_name = excluded.custom_name,
+                description = excluded.description,
+                tags = excluded.tags",
+            params![url, name, custom_name, description, tags.join(",")],
+        )?;
+
+        Ok(())
+    }
+
+    /// Updates whichever fields are `Some`, leaving the rest untouched.
+    /// Returns `false` if no bookmark is stored at that URL.
+    pub fn edit(
+        &self,
+        url: &str,
+        custom_name: Option<&str>,
+        description: Option<&str>,
+        tags: Option<&[String]>,
+    ) -> SqliteResult<bool> {
+        let current = self.conn.query_row(
+            "SELECT custom_name, description, tags FROM bookmarks WHERE url = ?1",
+            params![url],
+            |row| {
+                Ok((
+                    row.get::<_, Option<String>>(0)?,
+                    row.get::<_, Option<String>>(1)?,
+                    row.get::<_, String>(2)?,
+                ))
+            },
+        );
+        let Ok((current_custom_name, current_description, current_tags)) = current else {
+            return Ok(false);
+        };
+
+        let custom_name = custom_name.map(String::from).or(current_custom_name);
+        let description = description.map(String::from).or(current_description);
+        let tags = tags.map(|t| t.join(",")).unwrap_or(current_tags);
+
+        self.conn.execute(
+            "UPDATE bookmarks SET custom_name = ?2, description = ?3, tags = ?4 WHERE url = ?1",
+            params![url, custom_name, description, tags],
+        )?;
+
+        Ok(true)
+    }
+
+    pub fn remove(&self, url: &str) -> SqliteResult<bool> {
+        let removed = self
+            .conn
+            .execute("DELETE FROM bookmarks WHERE url = ?1", params![url])?;
+        Ok(removed > 0)
+    }
+}
+
+fn split_tags(raw: &str) -> Vec<String> {
+    if raw.is_empty() {
+        Vec::new()
+    } else {
+        raw.split(',').map(String::from).collect()
+    }
+}
+
+/// Default display name for a bookmark with no custom name: the URL's host
+/// plus a readable version of its path.
+fn readable_name_from_url(url: &str) -> String {
+    let without_scheme = url.split_once("://").map_or(url, |(_, rest)| rest);
+    let mut parts = without_scheme.splitn(2, '/');
+    let host = parts.next().unwrap_or(without_scheme);
+    let path = parts.next().unwrap_or("");
+
+    if path.is_empty() {
+        host.to_string()
+    } else {
+        let readable_path = path.trim_end_matches('/').replace(['-', '_', '/'], " ");
+        let readable_path = readable_path.trim();
+        if readable_path.is_empty() {
+            host.to_string()
+        } else {
+            format!("{} — {}", host, readable_path)
+        }
+    }
+}
+
+/// Indexes stored metadata by URL for quick lookup while merging it into a
+/// freshly-imported bookmark tree.
+pub fn overrides_by_url(stored: Vec<StoredBookmark>) -> HashMap<String, StoredBookmark> {
+    stored.into_iter().map(|b| (b.url.clone(), b)).collect()
+}
+
+/// Merges the user's custom names and tags into `trees` in place, so the
+/// rest of the app (the reflection prompt, the feed) sees the user's own
+/// labels rather than whatever the browser originally recorded.
+pub fn apply_overrides(trees: &mut [BookmarkTree], overrides: &HashMap<String, StoredBookmark>) {
+    for tree in trees {
+        apply_node(tree, overrides);
+    }
+}
+
+fn apply_node(tree: &mut BookmarkTree, overrides: &HashMap<String, StoredBookmark>) {
+    match tree {
+        BookmarkTree::Folder(folder) => {
+            for child in &mut folder.children {
+                apply_node(child, overrides);
+            }
+        }
+        BookmarkTree::Bookmark(bookmark) => {
+            let Some(stored) = overrides.get(&bookmark.url) else {
+                return;
+            };
+
+            if let Some(custom_name) = &stored.custom_name {
+                bookmark.name = custom_name.clone();
+            }
+            for tag in &stored.tags {
+                if !bookmark.tags.contains(tag) {
+                    bookmark.tags.push(tag.clone());
+                }
+            }
+        }
+    }
+}